@@ -0,0 +1,83 @@
+use clap::Args;
+
+use crate::mine::PriorityFeeStrategy;
+
+#[derive(Args, Debug, Clone)]
+pub struct MineArgs {
+    #[arg(
+        long,
+        value_name = "THREAD_COUNT",
+        help = "The number of threads to dedicate to mining",
+        default_value = "1"
+    )]
+    pub threads: u64,
+
+    #[arg(
+        long,
+        value_name = "SECOND_COUNT",
+        help = "The number of seconds before the deadline to stop mining and start submitting",
+        default_value = "5"
+    )]
+    pub buffer_time: u64,
+
+    #[arg(
+        long,
+        value_name = "MIN_DIFFICULTY",
+        help = "The difficulty a hash must exceed before mining stops at the deadline",
+        default_value = "8"
+    )]
+    pub min_difficulty: u32,
+
+    #[arg(
+        long,
+        value_name = "BEST_DIFFICULTY",
+        help = "The difficulty above which mining stops early for the round; with the fixed fee \
+                strategy, the priority fee is doubled for a hash above the midpoint of this and \
+                the minimum difficulty",
+        default_value = "16"
+    )]
+    pub best_difficulty: u32,
+
+    #[arg(
+        long = "priority-fee-strategy",
+        value_enum,
+        value_name = "STRATEGY",
+        help = "The strategy to use for pricing the compute unit price of a submission [possible values: fixed, dynamic]",
+        default_value = "fixed"
+    )]
+    pub priority_fee_strategy: PriorityFeeStrategy,
+
+    #[arg(
+        long,
+        value_name = "MICROLAMPORTS",
+        help = "The priority fee to pay for each submission under the fixed strategy, in \
+                microlamports; the dynamic strategy uses it only when no recent fees are available",
+        default_value = "0"
+    )]
+    pub priority_fee: u64,
+
+    #[arg(
+        long,
+        value_name = "MICROLAMPORTS",
+        help = "The maximum priority fee to pay for a dynamically priced submission, in microlamports",
+        default_value = "1000000"
+    )]
+    pub max_priority_fee: u64,
+
+    #[arg(
+        long,
+        help = "Farm out hashing to external workers connected over TCP instead of mining locally"
+    )]
+    pub external: bool,
+
+    #[arg(
+        long,
+        value_name = "ADDRESS",
+        help = "The address to bind the external worker socket to. The worker protocol has no \
+                authentication, so any host that can reach this address can claim work and \
+                submit solutions; defaults to loopback-only and should only be bound to a \
+                wider interface on a trusted network",
+        default_value = "127.0.0.1:9090"
+    )]
+    pub bind_addr: String,
+}
@@ -1,19 +1,31 @@
-use std::{sync::{Arc, atomic::{AtomicBool, Ordering}}, time::Instant};
+use std::{
+    io::{BufRead, BufReader, Write},
+    net::{TcpListener, TcpStream},
+    ops::Range,
+    sync::{
+        atomic::{AtomicBool, AtomicU64, Ordering},
+        Arc, Condvar, Mutex,
+    },
+    thread::JoinHandle,
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
+};
 
 use colored::*;
 use drillx::{
     equix::{self},
     Hash, Solution,
 };
+use indicatif::ProgressBar;
 use ore_api::{
     consts::{BUS_ADDRESSES, BUS_COUNT, EPOCH_DURATION},
     state::{Bus, Config, Proof},
 };
 use ore_utils::AccountDeserialize;
 use rand::Rng;
+use serde::{Deserialize, Serialize};
 use solana_program::pubkey::Pubkey;
 use solana_rpc_client::spinner;
-use solana_sdk::signer::Signer;
+use solana_sdk::{compute_budget::ComputeBudgetInstruction, signer::Signer};
 
 use crate::{
     args::MineArgs,
@@ -24,121 +36,207 @@ use crate::{
     Miner,
 };
 
-impl Miner {
-    pub async fn mine(&self, args: MineArgs) {
-        // Open account, if needed.
-        let signer = self.signer();
-        self.open().await;
-        let start = Instant::now();
-        let mut num_hash_created = 0;
-        let mut num_hash_best_difficulty_created = 0;
-        let mut best_difficulty_created = 0;
+// A drillx hash difficulty: the number of leading zero bits in a digest.
+// Wrapping this instead of comparing bare u32s ad hoc gives saturating
+// construction, a single formatting point, and a midpoint that can't
+// overflow the way (a + b) / 2 can for large inputs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default)]
+pub struct Difficulty(u32);
 
-        // Check num threads
-        self.check_num_cores(args.threads);
+impl Difficulty {
+    // A drillx digest is 16 bytes, so no hash can exceed 128 leading zero
+    // bits; constructing above this saturates instead of overflowing.
+    pub const MAX: Difficulty = Difficulty(128);
 
-        // Start mining loop
-        let mut last_hash_at = 0;
-        loop {
-            if num_hash_created > 0 {
-                println!("----------------------------------------------");
-                println!("- Number of hash created: {}", num_hash_created);
-                println!("- Number of hash exceed {} created: {}", args.best_difficulty, num_hash_best_difficulty_created);
-                println!("- Best difficulty created: {}", best_difficulty_created);
-                println!("- Time elapsed: {}", start.elapsed().as_secs());
-                println!("----------------------------------------------");
-            }
-            // Fetch proof
-            let config = get_config(&self.rpc_client).await;
-            let proof =
-                get_updated_proof_with_authority(&self.rpc_client, signer.pubkey(), last_hash_at)
-                    .await;
-            last_hash_at = proof.last_hash_at;
-            println!(
-                "\nStake: {} ORE\n  Multiplier: {:12}x",
-                amount_u64_to_string(proof.balance),
-                calculate_multiplier(proof.balance, config.top_balance)
-            );
+    pub fn new(value: u32) -> Self {
+        Self(value.min(Self::MAX.0))
+    }
 
-            // Calculate cutoff time
-            let cutoff_time = self.get_cutoff(proof, args.buffer_time).await;
+    pub fn value(self) -> u32 {
+        self.0
+    }
 
-            // Run drillx
-            let (solution, should_increase_fee, best_difficulty) = Self::find_hash_par(
-                proof,
-                cutoff_time,
-                args.threads,
-                args.min_difficulty,
-                args.best_difficulty,
-            )
-            .await;
-            num_hash_created += 1;
-            if best_difficulty.gt(&args.best_difficulty) {
-                num_hash_best_difficulty_created += 1;
-            }
-            if best_difficulty.gt(&best_difficulty_created) {
-                best_difficulty_created = best_difficulty
-            }
+    // The midpoint between two difficulties, without the overflow risk of a
+    // naive (a + b) / 2.
+    pub fn midpoint(a: Difficulty, b: Difficulty) -> Difficulty {
+        let (lo, hi) = if a.0 <= b.0 { (a.0, b.0) } else { (b.0, a.0) };
+        Difficulty(lo + (hi - lo) / 2)
+    }
 
-            // Build instruction set
-            let mut ixs = vec![ore_api::instruction::auth(proof_pubkey(signer.pubkey()))];
-            let mut compute_budget = 500_000;
-            if self.should_reset(config).await && rand::thread_rng().gen_range(0..100).eq(&0) {
-                compute_budget += 100_000;
-                ixs.push(ore_api::instruction::reset(signer.pubkey()));
-            }
+    // The largest hash value (out of u64::MAX) that still satisfies this
+    // difficulty, so a difficulty can be reasoned about as an equivalent
+    // hash-target instead of a raw bit count.
+    pub fn to_target_estimate(self) -> u64 {
+        u64::MAX.checked_shr(self.0).unwrap_or(0)
+    }
+}
 
-            // Build mine ix
-            ixs.push(ore_api::instruction::mine(
-                signer.pubkey(),
-                signer.pubkey(),
-                self.find_bus().await,
-                solution,
-            ));
+impl std::fmt::Display for Difficulty {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
 
-            // Submit transaction
-            self.send_and_confirm(&ixs, ComputeBudget::Fixed(compute_budget), false, should_increase_fee)
-                .await
-                .ok();
+// How `Miner::estimate_priority_fee` prices the compute-unit price on a mine
+// transaction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, clap::ValueEnum)]
+pub enum PriorityFeeStrategy {
+    // Use `MineArgs::priority_fee`, doubled when `should_increase_fee` is
+    // set.
+    #[default]
+    Fixed,
+    // Price from the 75th percentile of recent prioritization fees on the
+    // bus and proof accounts, scaled up for high-difficulty solutions.
+    Dynamic,
+}
+
+// Percentile of recent prioritization fees used as the base price for a
+// `Dynamic` priority-fee submission.
+const PRIORITY_FEE_PERCENTILE: f64 = 0.75;
+
+// Difficulty points per 1x bump to the dynamic priority fee: a
+// higher-difficulty solution is worth more and worth landing faster.
+const DIFFICULTY_FEE_SCALE_DIVISOR: u64 = 32;
+
+// Default number of attempts `poll_rpc` makes before giving up.
+const MAX_RPC_CALL_RETRIES: u32 = 5;
+
+// Base delay `poll_rpc` backs off by between attempts; doubled each retry.
+const RPC_RETRY_BASE_DELAY: Duration = Duration::from_millis(500);
+
+// Retry a transient RPC call with exponential backoff instead of letting a
+// brief node hiccup crash deep in a helper. Gives up and returns the last
+// error once `max_retries` is exhausted.
+async fn poll_rpc<T, E, F, Fut>(op: F, max_retries: u32, base_delay: Duration) -> Result<T, E>
+where
+    F: Fn() -> Fut,
+    Fut: std::future::Future<Output = Result<T, E>>,
+    E: std::fmt::Display,
+{
+    let mut attempt = 0;
+    loop {
+        match op().await {
+            Ok(value) => return Ok(value),
+            Err(err) => {
+                attempt += 1;
+                if attempt >= max_retries {
+                    return Err(err);
+                }
+                let delay = base_delay.saturating_mul(1u32 << (attempt - 1).min(5));
+                println!(
+                    "{} RPC call failed ({err}), retrying in {:.1}s ({attempt}/{max_retries})",
+                    "WARNING".bold().yellow(),
+                    delay.as_secs_f64(),
+                );
+                tokio::time::sleep(delay).await;
+            }
         }
     }
+}
 
-    async fn find_hash_par(
-        proof: Proof,
-        cutoff_time: u64,
-        threads: u64,
-        min: u32,
-        best: u32,
-    ) -> (Solution, bool, u32) {
-        // Dispatch job to each thread
-        let progress_bar = Arc::new(spinner::new_progress_bar());
+// How often the hash-rate sampler snapshots the shared counters.
+const HASH_RATE_SAMPLE_INTERVAL: Duration = Duration::from_secs(1);
+
+// Smoothing factor for the hash-rate EMA; lower is smoother but slower to
+// react to a real change in throughput.
+const HASH_RATE_EMA_ALPHA: f64 = 0.3;
+
+// A unit of hashing work for one round: the challenge, cutoff, difficulty
+// thresholds, and the nonce range that each worker takes an equal slice of.
+#[derive(Clone)]
+struct Job {
+    challenge: [u8; 32],
+    cutoff_time: u64,
+    min: Difficulty,
+    best: Difficulty,
+    nonce_range: Range<u64>,
+}
+
+// The latest published job, generation-tagged so a worker can tell a fresh
+// job from the one it just finished.
+struct JobSlot {
+    generation: u64,
+    job: Option<Job>,
+}
+
+// Persistent hashing workers, each with a long-lived `equix::SolverMemory`,
+// parked on a condvar between rounds instead of spawned and torn down every
+// time `mine` loops.
+struct WorkerPool {
+    threads: u64,
+    job: Arc<(Mutex<JobSlot>, Condvar)>,
+    found_best_solution: Arc<AtomicBool>,
+    results: Arc<Mutex<Vec<Option<(u64, Difficulty, Hash)>>>>,
+    remaining: Arc<(Mutex<u64>, Condvar)>,
+    progress_bar: Arc<Mutex<Arc<ProgressBar>>>,
+    hash_counts: Vec<Arc<AtomicU64>>,
+    hash_rate_ema: Arc<Mutex<f64>>,
+    _workers: Vec<JoinHandle<()>>,
+    _sampler: JoinHandle<()>,
+}
+
+impl WorkerPool {
+    // Spin up `threads` workers and park them until the first job arrives.
+    fn new(threads: u64) -> Self {
+        let job = Arc::new((
+            Mutex::new(JobSlot {
+                generation: 0,
+                job: None,
+            }),
+            Condvar::new(),
+        ));
         let found_best_solution = Arc::new(AtomicBool::new(false));
-        progress_bar.set_message("Mining...");
+        let results = Arc::new(Mutex::new(vec![None; threads as usize]));
+        let remaining = Arc::new((Mutex::new(0u64), Condvar::new()));
+        let progress_bar = Arc::new(Mutex::new(Arc::new(spinner::new_progress_bar())));
+        let hash_counts: Vec<Arc<AtomicU64>> =
+            (0..threads).map(|_| Arc::new(AtomicU64::new(0))).collect();
+        let hash_rate_ema = Arc::new(Mutex::new(0f64));
 
-        let handles: Vec<_> = (0..threads)
-            .into_iter()
+        let workers = (0..threads)
             .map(|i| {
-                std::thread::spawn({
-                    let proof = proof.clone();
-                    let progress_bar = progress_bar.clone();
-                    let found_best_solution_clone = found_best_solution.clone();
+                let job = job.clone();
+                let found_best_solution = found_best_solution.clone();
+                let results = results.clone();
+                let remaining = remaining.clone();
+                let progress_bar = progress_bar.clone();
+                let hash_count = hash_counts[i as usize].clone();
+                std::thread::spawn(move || {
                     let mut memory = equix::SolverMemory::new();
-                    move || {
+                    let mut last_seen = 0u64;
+                    loop {
+                        // Park until a new job is published.
+                        let current = {
+                            let (lock, cvar) = &*job;
+                            let mut guard = lock.lock().unwrap();
+                            while guard.generation == last_seen || guard.job.is_none() {
+                                guard = cvar.wait(guard).unwrap();
+                            }
+                            last_seen = guard.generation;
+                            guard.job.clone().unwrap()
+                        };
+                        let progress_bar = progress_bar.lock().unwrap().clone();
+
                         // Start hashing
                         let timer = Instant::now();
-                        let mut nonce = u64::MAX.saturating_div(threads).saturating_mul(i);
+                        let span = current
+                            .nonce_range
+                            .end
+                            .saturating_sub(current.nonce_range.start)
+                            .saturating_div(threads);
+                        let mut nonce = current.nonce_range.start.saturating_add(span.saturating_mul(i));
                         let mut best_nonce = nonce;
-                        let mut best_difficulty = 0;
+                        let mut best_difficulty = Difficulty::default();
                         let mut best_hash = Hash::default();
                         loop {
-                            if found_best_solution_clone.load(Ordering::Relaxed) {
-                                if timer.elapsed().as_secs().ge(&cutoff_time) {
+                            if found_best_solution.load(Ordering::Relaxed) {
+                                if timer.elapsed().as_secs().ge(&current.cutoff_time) {
                                     break;
                                 } else {
                                     if i == 0 {
                                         progress_bar.set_message(format!(
                                             "Idle-ing ({} sec remaining)",
-                                            cutoff_time.saturating_sub(timer.elapsed().as_secs()),
+                                            current.cutoff_time.saturating_sub(timer.elapsed().as_secs()),
                                         ));
                                     }
                                     std::thread::sleep(std::time::Duration::from_secs(1));
@@ -147,12 +245,14 @@ impl Miner {
                             }
 
                             // Create hash
-                            if let Ok(hx) = drillx::hash_with_memory(
+                            let hash_result = drillx::hash_with_memory(
                                 &mut memory,
-                                &proof.challenge,
+                                &current.challenge,
                                 &nonce.to_le_bytes(),
-                            ) {
-                                let difficulty = hx.difficulty();
+                            );
+                            hash_count.fetch_add(1, Ordering::Relaxed);
+                            if let Ok(hx) = hash_result {
+                                let difficulty = Difficulty::new(hx.difficulty());
                                 if difficulty.gt(&best_difficulty) {
                                     best_nonce = nonce;
                                     best_difficulty = difficulty;
@@ -160,23 +260,23 @@ impl Miner {
                                 }
                             }
 
-                            if best_difficulty.gt(&best) {
-                                found_best_solution_clone.store(true, Ordering::Relaxed);
+                            if best_difficulty.gt(&current.best) {
+                                found_best_solution.store(true, Ordering::Relaxed);
                                 continue;
                             }
 
                             // Exit if time has elapsed
                             if nonce % 100 == 0 {
-                                if timer.elapsed().as_secs().ge(&cutoff_time) {
-                                    if best_difficulty.gt(&min) {
-                                        found_best_solution_clone.store(true, Ordering::Relaxed);
+                                if timer.elapsed().as_secs().ge(&current.cutoff_time) {
+                                    if best_difficulty.gt(&current.min) {
+                                        found_best_solution.store(true, Ordering::Relaxed);
                                         // Mine until min difficulty has been met
                                         break;
                                     }
                                 } else if i == 0 {
                                     progress_bar.set_message(format!(
                                         "Mining... ({} sec remaining)",
-                                        cutoff_time.saturating_sub(timer.elapsed().as_secs()),
+                                        current.cutoff_time.saturating_sub(timer.elapsed().as_secs()),
                                     ));
                                 }
                             }
@@ -185,19 +285,122 @@ impl Miner {
                             nonce += 1;
                         }
 
-                        // Return the best nonce
-                        (best_nonce, best_difficulty, best_hash)
+                        // Publish this worker's best result and signal completion
+                        results.lock().unwrap()[i as usize] = Some((best_nonce, best_difficulty, best_hash));
+                        let (lock, cvar) = &*remaining;
+                        let mut count = lock.lock().unwrap();
+                        *count -= 1;
+                        if *count == 0 {
+                            cvar.notify_one();
+                        }
                     }
                 })
             })
             .collect();
 
-        // Join handles and return best nonce
+        // Sample the aggregate hash counter at a fixed interval and smooth it
+        // into an EMA so the reported H/s doesn't jitter round to round.
+        let sampler = {
+            let hash_counts = hash_counts.clone();
+            let hash_rate_ema = hash_rate_ema.clone();
+            std::thread::spawn(move || {
+                let mut prev_total = 0u64;
+                let mut prev_time = Instant::now();
+                loop {
+                    std::thread::sleep(HASH_RATE_SAMPLE_INTERVAL);
+                    let total: u64 = hash_counts.iter().map(|c| c.load(Ordering::Relaxed)).sum();
+                    let elapsed = prev_time.elapsed().as_secs_f64().max(0.001);
+                    let sample = total.saturating_sub(prev_total) as f64 / elapsed;
+                    let mut ema = hash_rate_ema.lock().unwrap();
+                    *ema += HASH_RATE_EMA_ALPHA * (sample - *ema);
+                    prev_total = total;
+                    prev_time = Instant::now();
+                }
+            })
+        };
+
+        Self {
+            threads,
+            job,
+            found_best_solution,
+            results,
+            remaining,
+            progress_bar,
+            hash_counts,
+            hash_rate_ema,
+            _workers: workers,
+            _sampler: sampler,
+        }
+    }
+
+    // Current smoothed total hash rate across all workers, in H/s.
+    fn hash_rate(&self) -> f64 {
+        *self.hash_rate_ema.lock().unwrap()
+    }
+
+    // The difficulty we'd expect to find if we kept hashing at the current
+    // rate for `window_secs` more seconds, used to warn early when a target
+    // difficulty is unlikely to be reached before the cutoff. Walks
+    // difficulties upward while this many hashes would still expect to clear
+    // that difficulty's target, rather than a direct log2 estimate.
+    fn expected_difficulty(&self, window_secs: u64) -> Difficulty {
+        let total_hashes = self.hash_rate() * window_secs as f64;
+        if total_hashes < 1.0 {
+            return Difficulty::default();
+        }
+        let mut difficulty = 0u32;
+        while difficulty < Difficulty::MAX.value() {
+            let candidate = Difficulty::new(difficulty + 1);
+            let expected_hits =
+                total_hashes * (candidate.to_target_estimate() as f64 / u64::MAX as f64);
+            if expected_hits < 1.0 {
+                break;
+            }
+            difficulty += 1;
+        }
+        Difficulty::new(difficulty)
+    }
+
+    // Publish a new job to every worker, wait for the round to finish, and
+    // return the best solution found.
+    fn dispatch(
+        &self,
+        proof: &Proof,
+        cutoff_time: u64,
+        min: Difficulty,
+        best: Difficulty,
+    ) -> (Solution, bool, Difficulty) {
+        let progress_bar = Arc::new(spinner::new_progress_bar());
+        progress_bar.set_message("Mining...");
+        *self.progress_bar.lock().unwrap() = progress_bar.clone();
+        self.found_best_solution.store(false, Ordering::Relaxed);
+        *self.remaining.0.lock().unwrap() = self.threads;
+
+        {
+            let (lock, cvar) = &*self.job;
+            let mut guard = lock.lock().unwrap();
+            guard.generation += 1;
+            guard.job = Some(Job {
+                challenge: proof.challenge,
+                cutoff_time,
+                min,
+                best,
+                nonce_range: 0..u64::MAX,
+            });
+            cvar.notify_all();
+        }
+
+        // Wait for every worker to finish this round
+        let (lock, cvar) = &*self.remaining;
+        let count = lock.lock().unwrap();
+        let _count = cvar.wait_while(count, |count| *count > 0).unwrap();
+
+        // Collect the best result across all workers
         let mut best_nonce = 0;
-        let mut best_difficulty = 0;
+        let mut best_difficulty = Difficulty::default();
         let mut best_hash = Hash::default();
-        for h in handles {
-            if let Ok((nonce, difficulty, hash)) = h.join() {
+        for slot in self.results.lock().unwrap().iter_mut() {
+            if let Some((nonce, difficulty, hash)) = slot.take() {
                 if difficulty > best_difficulty {
                     best_difficulty = difficulty;
                     best_nonce = nonce;
@@ -206,14 +409,427 @@ impl Miner {
             }
         }
 
-        // Update log
+        let hash_rate = self.hash_rate();
         progress_bar.finish_with_message(format!(
-            "Best hash: {} (difficulty: {})",
+            "Best hash: {} (difficulty: {})\n  Hash rate: {:.2} H/s ({:.2} H/s per thread across {} threads)",
             bs58::encode(best_hash.h).into_string(),
-            best_difficulty
+            best_difficulty,
+            hash_rate,
+            hash_rate / self.threads as f64,
+            self.threads,
         ));
 
-        (Solution::new(best_hash.d, best_nonce.to_le_bytes()), best_difficulty.gt(&((min + best)/2)), best_difficulty)
+        (
+            Solution::new(best_hash.d, best_nonce.to_le_bytes()),
+            best_difficulty.gt(&Difficulty::midpoint(min, best)),
+            best_difficulty,
+        )
+    }
+}
+
+// A work unit handed to an external worker over the wire: the challenge to
+// hash against, this worker's partition of the nonce space, and the
+// deadline/difficulty thresholds that govern when to stop.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct WorkUnit {
+    challenge: [u8; 32],
+    nonce_start: u64,
+    nonce_end: u64,
+    cutoff_unix: i64,
+    min_difficulty: u32,
+    best_difficulty: u32,
+}
+
+// A solution submitted back by an external worker.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct WorkSubmission {
+    nonce: u64,
+    digest: [u8; 16],
+    difficulty: u32,
+}
+
+// Coordinates external hashing workers (GPU rigs, other machines) over a
+// line-delimited JSON protocol instead of hashing on local CPU cores.
+// Mirrors WorkerPool's role but farms work out over a socket rather than to
+// in-process threads.
+struct ExternalCoordinator {
+    listener: TcpListener,
+    workers: Mutex<Vec<TcpStream>>,
+}
+
+impl ExternalCoordinator {
+    fn bind(addr: &str) -> std::io::Result<Self> {
+        let listener = TcpListener::bind(addr)?;
+        listener.set_nonblocking(true)?;
+        println!("{} listening for external workers on {addr}", "INFO".bold().green());
+        Ok(Self {
+            listener,
+            workers: Mutex::new(Vec::new()),
+        })
+    }
+
+    // Accept any workers that connected since the last round, partition the
+    // nonce space across them, and collect the best submission received
+    // within `cutoff_time` plus a 5-second grace. Returns `None` if no worker is
+    // connected, so the caller can skip the round instead of submitting a
+    // placeholder.
+    fn dispatch(
+        &self,
+        proof: &Proof,
+        cutoff_time: u64,
+        min: Difficulty,
+        best: Difficulty,
+    ) -> Option<(Solution, bool, Difficulty)> {
+        while let Ok((stream, addr)) = self.listener.accept() {
+            println!("{} external worker connected: {addr}", "INFO".bold().green());
+            self.workers.lock().unwrap().push(stream);
+        }
+
+        let workers: Vec<TcpStream> = std::mem::take(&mut *self.workers.lock().unwrap());
+        if workers.is_empty() {
+            println!(
+                "{} no external workers connected; waiting for one to join...",
+                "WARNING".bold().yellow()
+            );
+            std::thread::sleep(Duration::from_secs(cutoff_time.max(1)));
+            return None;
+        }
+
+        let deadline_unix = unix_now() + cutoff_time as i64;
+        let n = workers.len() as u64;
+        let span = u64::MAX / n;
+
+        let handles: Vec<_> = workers
+            .into_iter()
+            .enumerate()
+            .map(|(i, stream)| {
+                let work = WorkUnit {
+                    challenge: proof.challenge,
+                    nonce_start: span.saturating_mul(i as u64),
+                    nonce_end: if i as u64 + 1 == n {
+                        u64::MAX
+                    } else {
+                        span.saturating_mul(i as u64 + 1)
+                    },
+                    cutoff_unix: deadline_unix,
+                    min_difficulty: min.value(),
+                    best_difficulty: best.value(),
+                };
+                std::thread::spawn(move || Self::dispatch_to_worker(stream, work))
+            })
+            .collect();
+
+        let mut live_workers = Vec::new();
+        let mut best_solution: Option<Solution> = None;
+        let mut best_difficulty = Difficulty::default();
+        for h in handles {
+            if let Ok(Some((stream, solution, difficulty))) = h.join() {
+                live_workers.push(stream);
+                if difficulty > best_difficulty {
+                    best_difficulty = difficulty;
+                    best_solution = Some(solution);
+                }
+            }
+        }
+        *self.workers.lock().unwrap() = live_workers;
+
+        let solution = best_solution?;
+        Some((
+            solution,
+            best_difficulty.gt(&Difficulty::midpoint(min, best)),
+            best_difficulty,
+        ))
+    }
+
+    // Send one worker its work unit and block for its submission,
+    // validating the returned digest before accepting it.
+    fn dispatch_to_worker(
+        mut stream: TcpStream,
+        work: WorkUnit,
+    ) -> Option<(TcpStream, Solution, Difficulty)> {
+        let mut line = serde_json::to_string(&work).ok()?;
+        line.push('\n');
+        stream.write_all(line.as_bytes()).ok()?;
+
+        let remaining = (work.cutoff_unix - unix_now()).max(1) as u64;
+        stream
+            .set_read_timeout(Some(Duration::from_secs(remaining + 5)))
+            .ok();
+
+        let mut reader = BufReader::new(stream.try_clone().ok()?);
+        let mut response = String::new();
+        reader.read_line(&mut response).ok()?;
+        let submission: WorkSubmission = serde_json::from_str(response.trim()).ok()?;
+
+        let solution = Solution::new(submission.digest, submission.nonce.to_le_bytes());
+        if !solution.is_valid(&work.challenge) {
+            return None;
+        }
+        let difficulty = Difficulty::new(solution.to_hash().difficulty());
+        Some((stream, solution, difficulty))
+    }
+}
+
+// Seconds since the Unix epoch, used to convert the coordinator's relative
+// `cutoff_time` into an absolute deadline external workers can honor
+// regardless of network latency.
+fn unix_now() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_secs() as i64
+}
+
+// Either a local thread pool or an external-worker coordinator; `mine`
+// dispatches rounds through whichever backend `--external` selected.
+enum MiningBackend {
+    Local(WorkerPool),
+    External(ExternalCoordinator),
+}
+
+impl MiningBackend {
+    // Returns `None` when the round produced nothing worth submitting (e.g.
+    // no external worker is connected yet), so the caller should skip
+    // straight to the next round instead of submitting a placeholder.
+    fn dispatch(
+        &self,
+        proof: &Proof,
+        cutoff_time: u64,
+        min: Difficulty,
+        best: Difficulty,
+    ) -> Option<(Solution, bool, Difficulty)> {
+        match self {
+            MiningBackend::Local(pool) => Some(pool.dispatch(proof, cutoff_time, min, best)),
+            MiningBackend::External(coordinator) => coordinator.dispatch(proof, cutoff_time, min, best),
+        }
+    }
+
+    fn hash_rate(&self) -> f64 {
+        match self {
+            MiningBackend::Local(pool) => pool.hash_rate(),
+            MiningBackend::External(_) => 0.0,
+        }
+    }
+
+    fn expected_difficulty(&self, window_secs: u64) -> Difficulty {
+        match self {
+            MiningBackend::Local(pool) => pool.expected_difficulty(window_secs),
+            MiningBackend::External(_) => Difficulty::default(),
+        }
+    }
+}
+
+impl Miner {
+    pub async fn mine(&self, args: MineArgs) {
+        // Open account, if needed.
+        let signer = self.signer();
+        self.open().await;
+        let start = Instant::now();
+        let mut num_hash_created = 0;
+        let mut num_hash_best_difficulty_created = 0;
+        let mut best_difficulty_created = Difficulty::default();
+        let min_difficulty = Difficulty::new(args.min_difficulty);
+        let target_best_difficulty = Difficulty::new(args.best_difficulty);
+
+        // Check num threads
+        self.check_num_cores(args.threads);
+
+        // Spin up the mining backend once; a local pool stays parked between
+        // rounds, an external coordinator listens for workers to connect.
+        let pool = if args.external {
+            MiningBackend::External(
+                ExternalCoordinator::bind(&args.bind_addr)
+                    .expect("failed to bind external worker socket"),
+            )
+        } else {
+            MiningBackend::Local(WorkerPool::new(args.threads))
+        };
+
+        // Start mining loop
+        let mut last_hash_at = 0;
+        loop {
+            if num_hash_created > 0 {
+                println!("----------------------------------------------");
+                println!("- Number of hash created: {}", num_hash_created);
+                println!("- Number of hash exceed {} created: {}", args.best_difficulty, num_hash_best_difficulty_created);
+                println!("- Best difficulty created: {}", best_difficulty_created);
+                println!("- Hash rate: {:.2} H/s", pool.hash_rate());
+                println!("- Time elapsed: {}", start.elapsed().as_secs());
+                println!("----------------------------------------------");
+            }
+            // Fetch proof, retrying through transient RPC failures rather
+            // than crashing on a brief node outage. Skip this round if
+            // retries are exhausted.
+            let config = match poll_rpc(
+                || get_config(&self.rpc_client),
+                MAX_RPC_CALL_RETRIES,
+                RPC_RETRY_BASE_DELAY,
+            )
+            .await
+            {
+                Ok(config) => config,
+                Err(err) => {
+                    println!(
+                        "{} giving up on fetching config after {MAX_RPC_CALL_RETRIES} attempts ({err}); skipping this round",
+                        "ERROR".bold().red()
+                    );
+                    continue;
+                }
+            };
+            let proof = match poll_rpc(
+                || get_updated_proof_with_authority(&self.rpc_client, signer.pubkey(), last_hash_at),
+                MAX_RPC_CALL_RETRIES,
+                RPC_RETRY_BASE_DELAY,
+            )
+            .await
+            {
+                Ok(proof) => proof,
+                Err(err) => {
+                    println!(
+                        "{} giving up on fetching proof after {MAX_RPC_CALL_RETRIES} attempts ({err}); skipping this round",
+                        "ERROR".bold().red()
+                    );
+                    continue;
+                }
+            };
+            last_hash_at = proof.last_hash_at;
+            println!(
+                "\nStake: {} ORE\n  Multiplier: {:12}x",
+                amount_u64_to_string(proof.balance),
+                calculate_multiplier(proof.balance, config.top_balance)
+            );
+
+            // Calculate cutoff time
+            let cutoff_time = match self.get_cutoff(proof, args.buffer_time).await {
+                Some(cutoff_time) => cutoff_time,
+                None => continue,
+            };
+
+            // Warn up front if the current hash rate can't realistically
+            // reach the configured difficulty targets before the cutoff.
+            if num_hash_created > 0 {
+                let expected_difficulty = pool.expected_difficulty(cutoff_time);
+                if expected_difficulty < min_difficulty {
+                    println!(
+                        "{} At the current hash rate ({:.2} H/s), this round is only expected to reach difficulty {} before the cutoff (min: {})",
+                        "WARNING".bold().yellow(),
+                        pool.hash_rate(),
+                        expected_difficulty,
+                        min_difficulty,
+                    );
+                }
+            }
+
+            // Run drillx
+            let (solution, should_increase_fee, best_difficulty) = match pool.dispatch(
+                &proof,
+                cutoff_time,
+                min_difficulty,
+                target_best_difficulty,
+            ) {
+                Some(result) => result,
+                None => continue,
+            };
+            num_hash_created += 1;
+            if best_difficulty.gt(&target_best_difficulty) {
+                num_hash_best_difficulty_created += 1;
+            }
+            if best_difficulty.gt(&best_difficulty_created) {
+                best_difficulty_created = best_difficulty
+            }
+
+            // Build instruction set
+            let mut ixs = vec![ore_api::instruction::auth(proof_pubkey(signer.pubkey()))];
+            let mut compute_budget = 500_000;
+            if self.should_reset(config).await && rand::thread_rng().gen_range(0..100).eq(&0) {
+                compute_budget += 100_000;
+                ixs.push(ore_api::instruction::reset(signer.pubkey()));
+            }
+
+            // Build mine ix
+            let bus = self.find_bus().await;
+            ixs.push(ore_api::instruction::mine(
+                signer.pubkey(),
+                signer.pubkey(),
+                bus,
+                solution,
+            ));
+
+            // Price the submission. `Dynamic` samples recent prioritization
+            // fees instead of relying on a crude increase/don't-increase
+            // flag, and we push our own SetComputeUnitPrice instruction for
+            // it, so the legacy should_increase_fee flag below is disabled
+            // (always false) to avoid send_and_confirm adding a second,
+            // conflicting one.
+            let priority_fee = self
+                .estimate_priority_fee(
+                    &args,
+                    should_increase_fee,
+                    best_difficulty,
+                    bus,
+                    proof_pubkey(signer.pubkey()),
+                )
+                .await;
+            ixs.push(ComputeBudgetInstruction::set_compute_unit_price(priority_fee));
+
+            // Submit transaction
+            self.send_and_confirm(&ixs, ComputeBudget::Fixed(compute_budget), false, false)
+                .await
+                .ok();
+        }
+    }
+
+    // Price the compute-unit price (in microlamports) for this round's
+    // submission. `Fixed` keeps the legacy priority_fee/should_increase_fee
+    // behavior; `Dynamic` samples recent prioritization fees on the accounts
+    // this transaction writes to and scales the result up for
+    // high-difficulty solutions, capped at max_priority_fee.
+    async fn estimate_priority_fee(
+        &self,
+        args: &MineArgs,
+        should_increase_fee: bool,
+        best_difficulty: Difficulty,
+        bus: Pubkey,
+        proof: Pubkey,
+    ) -> u64 {
+        match args.priority_fee_strategy {
+            PriorityFeeStrategy::Fixed => {
+                if should_increase_fee {
+                    args.priority_fee.saturating_mul(2)
+                } else {
+                    args.priority_fee
+                }
+            }
+            PriorityFeeStrategy::Dynamic => {
+                let accounts = [bus, proof];
+                let fees = poll_rpc(
+                    || self.rpc_client.get_recent_prioritization_fees(&accounts),
+                    MAX_RPC_CALL_RETRIES,
+                    RPC_RETRY_BASE_DELAY,
+                )
+                .await
+                .unwrap_or_default();
+
+                let mut samples: Vec<u64> =
+                    fees.iter().map(|fee| fee.prioritization_fee).collect();
+                samples.sort_unstable();
+                let base_price = if samples.is_empty() {
+                    args.priority_fee
+                } else {
+                    let idx = (((samples.len() - 1) as f64) * PRIORITY_FEE_PERCENTILE).round() as usize;
+                    samples[idx.min(samples.len() - 1)]
+                };
+
+                // A higher-difficulty solution is more valuable, so it's
+                // worth paying more to land it faster.
+                let scaled_price = base_price.saturating_add(
+                    base_price.saturating_mul(best_difficulty.value() as u64)
+                        / DIFFICULTY_FEE_SCALE_DIVISOR,
+                );
+
+                scaled_price.min(args.max_priority_fee)
+            }
+        }
     }
 
     pub fn check_num_cores(&self, cores: u64) {
@@ -227,8 +843,25 @@ impl Miner {
         }
     }
 
+    // Falls back to `false` (skip the reset check this round) if the clock
+    // can't be fetched, rather than crashing the miner over it.
     async fn should_reset(&self, config: Config) -> bool {
-        let clock = get_clock(&self.rpc_client).await;
+        let clock = match poll_rpc(
+            || get_clock(&self.rpc_client),
+            MAX_RPC_CALL_RETRIES,
+            RPC_RETRY_BASE_DELAY,
+        )
+        .await
+        {
+            Ok(clock) => clock,
+            Err(err) => {
+                println!(
+                    "{} giving up on fetching clock after {MAX_RPC_CALL_RETRIES} attempts ({err}); skipping reset check this round",
+                    "ERROR".bold().red()
+                );
+                return false;
+            }
+        };
         config
             .last_reset_at
             .saturating_add(EPOCH_DURATION)
@@ -236,19 +869,47 @@ impl Miner {
             .le(&clock.unix_timestamp)
     }
 
-    async fn get_cutoff(&self, proof: Proof, buffer_time: u64) -> u64 {
-        let clock = get_clock(&self.rpc_client).await;
-        proof
-            .last_hash_at
-            .saturating_add(60)
-            .saturating_sub(buffer_time as i64)
-            .saturating_sub(clock.unix_timestamp)
-            .max(0) as u64
+    // Returns `None` if the clock can't be fetched after exhausting
+    // retries, so the caller can skip this round instead of mining against
+    // a stale or missing cutoff.
+    async fn get_cutoff(&self, proof: Proof, buffer_time: u64) -> Option<u64> {
+        let clock = match poll_rpc(
+            || get_clock(&self.rpc_client),
+            MAX_RPC_CALL_RETRIES,
+            RPC_RETRY_BASE_DELAY,
+        )
+        .await
+        {
+            Ok(clock) => clock,
+            Err(err) => {
+                println!(
+                    "{} giving up on fetching clock after {MAX_RPC_CALL_RETRIES} attempts ({err}); skipping this round",
+                    "ERROR".bold().red()
+                );
+                return None;
+            }
+        };
+        Some(
+            proof
+                .last_hash_at
+                .saturating_add(60)
+                .saturating_sub(buffer_time as i64)
+                .saturating_sub(clock.unix_timestamp)
+                .max(0) as u64,
+        )
     }
 
     async fn find_bus(&self) -> Pubkey {
-        // Fetch the bus with the largest balance
-        if let Ok(accounts) = self.rpc_client.get_multiple_accounts(&BUS_ADDRESSES).await {
+        // Fetch the bus with the largest balance, retrying through
+        // transient RPC failures instead of immediately guessing a random
+        // bus.
+        let accounts = poll_rpc(
+            || self.rpc_client.get_multiple_accounts(&BUS_ADDRESSES),
+            MAX_RPC_CALL_RETRIES,
+            RPC_RETRY_BASE_DELAY,
+        )
+        .await;
+        if let Ok(accounts) = accounts {
             let mut top_bus_balance: u64 = 0;
             let mut top_bus = BUS_ADDRESSES[0];
             for account in accounts {